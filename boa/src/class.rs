@@ -214,6 +214,95 @@ impl<'context> ClassBuilder<'context> {
             .insert_property(key.into(), property);
     }
 
+    /// Add a getter/setter accessor property to the class, with the specified attribute.
+    ///
+    /// It is added to `prototype`.
+    pub fn accessor<K>(
+        &mut self,
+        key: K,
+        get: Option<NativeFunction>,
+        set: Option<NativeFunction>,
+        attribute: Attribute,
+    ) where
+        K: Into<PropertyKey>,
+    {
+        let property = self.make_accessor_property(get, set, attribute);
+        self.prototype.borrow_mut().insert_property(key.into(), property);
+    }
+
+    /// Add a static getter/setter accessor property to the class, with the specified attribute.
+    ///
+    /// It is added to class object itself.
+    pub fn static_accessor<K>(
+        &mut self,
+        key: K,
+        get: Option<NativeFunction>,
+        set: Option<NativeFunction>,
+        attribute: Attribute,
+    ) where
+        K: Into<PropertyKey>,
+    {
+        let property = self.make_accessor_property(get, set, attribute);
+        self.object.borrow_mut().insert_property(key.into(), property);
+    }
+
+    /// Builds the native `get`/`set` functions for an accessor and wraps them in a
+    /// `Property::accessor_descriptor`.
+    fn make_accessor_property(
+        &mut self,
+        get: Option<NativeFunction>,
+        set: Option<NativeFunction>,
+        attribute: Attribute,
+    ) -> Property {
+        let function_prototype = self
+            .context
+            .global()
+            .get_field("Function")
+            .get_field(PROTOTYPE);
+
+        let get = get.map(|get| {
+            let function = Object::function(
+                Function::BuiltIn(get.into(), FunctionFlags::CALLABLE),
+                function_prototype.clone(),
+            );
+            GcObject::new(function)
+        });
+        let set = set.map(|set| {
+            let function = Object::function(
+                Function::BuiltIn(set.into(), FunctionFlags::CALLABLE),
+                function_prototype,
+            );
+            GcObject::new(function)
+        });
+
+        // We bitwise or (`|`) with `Attribute::default()` (`READONLY | NON_ENUMERABLE | PERMANENT`)
+        // so we dont get an empty attribute.
+        Property::accessor_descriptor(get, set, attribute | Attribute::default())
+    }
+
+    /// Specify the parent class that this class's `prototype`, and this class's static members,
+    /// inherit from, instead of the default `Object.prototype`.
+    ///
+    /// `U` must already be registered as a global class (e.g. via `Context::register_global_class`)
+    /// before this is called.
+    pub fn inherit<U>(&mut self)
+    where
+        U: Class,
+    {
+        let parent_constructor = self.context.global().get_field(U::NAME);
+        let parent_prototype = parent_constructor.get_field(PROTOTYPE);
+
+        self.prototype
+            .borrow_mut()
+            .set_prototype_instance(parent_prototype);
+
+        // The constructor's own `[[Prototype]]` points at the parent constructor, so static
+        // members (and the parent's own static inheritance chain) are inherited too.
+        self.object
+            .borrow_mut()
+            .set_prototype_instance(parent_constructor);
+    }
+
     pub fn context(&mut self) -> &'_ mut Interpreter {
         self.context
     }
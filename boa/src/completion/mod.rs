@@ -0,0 +1,226 @@
+//! Code-completion over the parser and the current evaluation environment.
+//!
+//! Given source text and a cursor (byte offset), [`complete`] classifies the syntactic context
+//! at the cursor -- an identifier being typed, or a member access after a `.` -- and returns a
+//! ranked list of [`CompletionItem`]s an editor/LSP front-end can offer. The context at the
+//! cursor is found by scanning the source text backwards from the cursor. This cross-cuts
+//! `syntax::parser` (to parse the receiver of a member access), `environment` (to walk the lexical
+//! scope chain for in-scope bindings) and `builtins::object` (to enumerate an object's own and
+//! prototype-chain property keys for member access).
+
+use crate::{syntax::Parser, Context};
+use std::ops::Range;
+
+/// A single completion candidate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletionItem {
+    /// The text shown to the user and inserted on accept.
+    pub label: String,
+    /// What kind of binding this candidate is.
+    pub kind: CompletionItemKind,
+    /// The byte range in the source that should be replaced with `label`.
+    pub range: Range<usize>,
+}
+
+/// The kind of a [`CompletionItem`], mirroring how IDE completion engines classify candidates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionItemKind {
+    /// A binding in the lexical scope chain.
+    Variable,
+    /// A callable binding.
+    Function,
+    /// An own or inherited property of an object, offered after `.`.
+    Property,
+    /// A reserved word (e.g. `function`, `const`, `new`).
+    Keyword,
+}
+
+/// The reserved words offered as completions at identifier position.
+const KEYWORDS: &[&str] = &[
+    "break", "case", "catch", "class", "const", "continue", "debugger", "default", "delete", "do",
+    "else", "export", "extends", "finally", "for", "function", "if", "import", "in", "instanceof",
+    "new", "return", "super", "switch", "this", "throw", "try", "typeof", "var", "void", "while",
+    "with", "yield", "let", "static", "async", "await",
+];
+
+/// Classifies the syntactic context at `offset` in `src` and returns ranked completion
+/// candidates, using `context`'s current lexical environment and global object.
+pub fn complete(context: &mut Context, src: &str, offset: usize) -> Vec<CompletionItem> {
+    let (prefix, replace_start) = identifier_prefix(src, offset);
+
+    match member_receiver(src, replace_start) {
+        Some(receiver_src) => complete_member(context, &receiver_src, &prefix, replace_start..offset),
+        None => complete_identifier(context, &prefix, replace_start..offset),
+    }
+}
+
+/// Walks backwards from `offset` over identifier characters to find the partial identifier
+/// being typed, returning it along with the byte offset it starts at.
+fn identifier_prefix(src: &str, offset: usize) -> (String, usize) {
+    let mut start = offset;
+    for (i, c) in src[..offset].char_indices().rev() {
+        if c.is_alphanumeric() || c == '_' || c == '$' {
+            start = i;
+        } else {
+            break;
+        }
+    }
+    (src[start..offset].to_string(), start)
+}
+
+/// If the identifier starting at `prefix_start` is preceded by `receiver.`, returns `receiver`'s
+/// source. Otherwise returns `None`, meaning completion should consider the identifier/keyword
+/// namespace instead.
+///
+/// `receiver` is isolated by walking backwards over a plain `ident(.ident)*` chain (e.g. `a`,
+/// `a.b`, `this.a.b`) -- it deliberately does *not* extend across operators, parentheses or
+/// brackets, so something like `1 + obj.` yields the receiver `obj`, not `1 + obj`. This also
+/// means call expressions (`f().`) and computed access (`a[0].`) are never picked up as a
+/// receiver. That rules out running arbitrary sub-expressions, but it is not a full
+/// side-effect guarantee: [`complete_member`] still evaluates the chain, so a getter defined
+/// on `a` or `a.b` still runs when completing `a.b.`.
+fn member_receiver(src: &str, prefix_start: usize) -> Option<String> {
+    let before = src[..prefix_start].trim_end();
+    let before = before.strip_suffix('.')?;
+
+    let mut start = before.len();
+    let mut cursor = before.len();
+    let mut expect_identifier = true;
+
+    loop {
+        let trimmed = before[..cursor].trim_end();
+        let trimmed_len = trimmed.len();
+
+        if expect_identifier {
+            let ident_start = trimmed
+                .char_indices()
+                .rev()
+                .take_while(|&(_, c)| c.is_alphanumeric() || c == '_' || c == '$')
+                .last()
+                .map_or(trimmed_len, |(i, _)| i);
+            if ident_start == trimmed_len {
+                // Expected an identifier but found none (e.g. an operator, a closing `)`/`]`
+                // from a call or computed access): not a simple member-access chain.
+                return None;
+            }
+            start = ident_start;
+            cursor = ident_start;
+            expect_identifier = false;
+        } else if let Some(rest) = trimmed.strip_suffix('.') {
+            cursor = rest.len();
+            expect_identifier = true;
+        } else {
+            // Anything else ends the chain; `start` already points at its first identifier.
+            break;
+        }
+    }
+
+    Some(before[start..].to_string())
+}
+
+/// Completion at identifier position: in-scope bindings from the lexical environment chain,
+/// plus the global object's own keys, plus language keywords.
+fn complete_identifier(
+    context: &mut Context,
+    prefix: &str,
+    range: Range<usize>,
+) -> Vec<CompletionItem> {
+    let mut items = Vec::new();
+
+    for name in context.realm().environment.get_all_binding_names() {
+        if name.starts_with(prefix) {
+            let kind = if context
+                .realm()
+                .environment
+                .get_binding_value(&name)
+                .map_or(false, |v| v.is_function())
+            {
+                CompletionItemKind::Function
+            } else {
+                CompletionItemKind::Variable
+            };
+            items.push(CompletionItem {
+                label: name,
+                kind,
+                range: range.clone(),
+            });
+        }
+    }
+
+    let global = context.global();
+    for key in global.borrow().keys() {
+        let key = key.to_string();
+        if key.starts_with(prefix) && !items.iter().any(|item| item.label == key) {
+            let kind = if global.borrow().get(&key.clone().into()).is_function() {
+                CompletionItemKind::Function
+            } else {
+                CompletionItemKind::Variable
+            };
+            items.push(CompletionItem {
+                label: key,
+                kind,
+                range: range.clone(),
+            });
+        }
+    }
+
+    for keyword in KEYWORDS {
+        if keyword.starts_with(prefix) {
+            items.push(CompletionItem {
+                label: (*keyword).to_string(),
+                kind: CompletionItemKind::Keyword,
+                range: range.clone(),
+            });
+        }
+    }
+
+    items
+}
+
+/// Completion after `receiver.`: evaluates `receiver`, then enumerates its own and
+/// prototype-chain property keys.
+///
+/// `receiver` is produced by [`member_receiver`], which only ever yields a plain `ident(.ident)*`
+/// chain -- never a call expression or other arbitrary sub-expression -- so evaluating it here
+/// against the live `context` is only a side-effect-free *reference* read of plain bindings,
+/// though property getters along the chain still execute (e.g. completing `a.b.` still invokes
+/// a getter for `b` on `a`).
+fn complete_member(
+    context: &mut Context,
+    receiver_src: &str,
+    prefix: &str,
+    range: Range<usize>,
+) -> Vec<CompletionItem> {
+    let mut items = Vec::new();
+
+    let receiver = match Parser::new(receiver_src.as_bytes()).parse_all() {
+        Ok(statements) => match statements.run(context) {
+            Ok(value) => value,
+            Err(_) => return items,
+        },
+        Err(_) => return items,
+    };
+
+    let mut object = receiver.as_object();
+
+    while let Some(current) = object {
+        for key in current.borrow().keys() {
+            let key = key.to_string();
+            if key.starts_with(prefix) && !items.iter().any(|item| item.label == key) {
+                let kind = if current.borrow().get(&key.clone().into()).is_function() {
+                    CompletionItemKind::Function
+                } else {
+                    CompletionItemKind::Property
+                };
+                items.push(CompletionItem {
+                    label: key,
+                    kind,
+                    range: range.clone(),
+                });
+            }
+        }
+        object = current.prototype_instance();
+    }
+
+    items
+}
@@ -0,0 +1,12 @@
+use super::{spread::run_spread_aware_list, Context, Executable};
+use crate::{builtins::value::Value, syntax::ast::node::ArrayDecl, Result};
+
+impl Executable for ArrayDecl {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        // Expands any `Node::Spread` entries in the element list, so `[...a, 1]` splices `a`'s
+        // elements into the resulting array rather than storing `a` itself as one element.
+        let values = run_spread_aware_list(self.as_ref(), interpreter)?;
+
+        Ok(Value::from(values))
+    }
+}
@@ -0,0 +1,17 @@
+use super::{spread::run_spread_aware_list, Context, Executable};
+use crate::{builtins::value::Value, syntax::ast::node::Call, Result};
+
+impl Executable for Call {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let func_object = self.expr().run(interpreter)?;
+
+        // Expands any `Node::Spread` entries in the argument list, so `f(...args)` calls `f`
+        // with `args`'s elements rather than with a single spread "value".
+        let args = run_spread_aware_list(self.args(), interpreter)?;
+
+        func_object
+            .as_object()
+            .ok_or_else(|| interpreter.construct_type_error("not a function"))?
+            .call(&func_object, &args, interpreter)
+    }
+}
@@ -0,0 +1,26 @@
+use super::{spread::run_spread_aware_list, Context, Executable};
+use crate::{
+    builtins::value::Value,
+    syntax::ast::node::{Call, New, Node},
+    Result,
+};
+
+impl Executable for New {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let (callee, args) = match self.call() {
+            Node::Call(call) => (call.expr(), call.args()),
+            _ => unreachable!("a new expression always wraps a call expression"),
+        };
+
+        let func_object = callee.run(interpreter)?;
+
+        // Constructor arguments go through the same spread-aware evaluation as call arguments,
+        // so `new C(...args)` expands `args` exactly like `C(...args)` would.
+        let v_args = run_spread_aware_list(args, interpreter)?;
+
+        func_object
+            .as_object()
+            .ok_or_else(|| interpreter.construct_type_error("not a constructor"))?
+            .construct(&v_args, &func_object, interpreter)
+    }
+}
@@ -0,0 +1,38 @@
+use super::{Context, Executable};
+use crate::{
+    builtins::{
+        object::{GcObject, Object, PROTOTYPE},
+        property::{Attribute, Property},
+        value::Value,
+    },
+    syntax::ast::node::{
+        object::{Object as ObjectLiteral, PropertyDefinition},
+        Node,
+    },
+    Result,
+};
+
+impl Executable for ObjectLiteral {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let object_prototype = interpreter.global().get_field("Object").get_field(PROTOTYPE);
+        let object = GcObject::new(Object::create(object_prototype));
+
+        for property in self.properties() {
+            match property {
+                PropertyDefinition::Property(key, value_node) => {
+                    let value = value_node.run(interpreter)?;
+                    let property = Property::data_descriptor(value, Attribute::all());
+                    object.borrow_mut().insert_property(key.clone(), property);
+                }
+                // `{ ...source }`: copies `source`'s own enumerable data properties onto `object`,
+                // rather than inserting `source` itself as a single keyed value.
+                PropertyDefinition::SpreadObject(expr) => match expr {
+                    Node::Spread(spread) => spread.copy_enumerable_properties(&object, interpreter)?,
+                    _ => unreachable!("object spread is always represented as `Node::Spread`"),
+                },
+            }
+        }
+
+        Ok(object.into())
+    }
+}
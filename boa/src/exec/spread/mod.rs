@@ -1,9 +1,113 @@
 use super::{Context, Executable};
-use crate::{builtins::value::Value, syntax::ast::node::Spread, Result};
+use crate::{
+    builtins::{iterable::get_iterator, object::GcObject, value::Value},
+    syntax::ast::node::{Node, Spread},
+    Result,
+};
 
 impl Executable for Spread {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        // TODO: for now we can do nothing but return the value as-is
+        // `Spread` never evaluates to a single value on its own: it expands into zero or more
+        // values (or properties) in the surrounding array literal, argument list or object
+        // literal. Callers that build one of those should use `Spread::run_array_values` (or
+        // `Spread::copy_enumerable_properties` for object spread) instead of going through
+        // `Executable::run` directly.
         self.val().run(interpreter)
     }
 }
+
+impl Spread {
+    /// Evaluates the spread's inner expression and drains it into a `Vec` of values.
+    ///
+    /// Follows the iterator protocol: if the value has a `@@iterator` method it is used to
+    /// produce the elements (this is also what makes generators and other custom iterables
+    /// spreadable), falling back to treating the value as an array-like and indexing `0..length`
+    /// only when it has no `@@iterator`.
+    ///
+    /// Used by array literal and call argument evaluation to splice `...expr` into place.
+    pub(crate) fn run_array_values(&self, interpreter: &mut Context) -> Result<Vec<Value>> {
+        let value = self.val().run(interpreter)?;
+
+        match get_iterator(interpreter, value.clone()) {
+            Ok(iterator) => {
+                let mut values = Vec::new();
+                loop {
+                    let (done, next_value) = iterator.next(interpreter)?;
+                    if done {
+                        break;
+                    }
+                    values.push(next_value);
+                }
+                Ok(values)
+            }
+            // Strictly, spreading a non-iterable should throw `TypeError: object is not
+            // iterable` even if it happens to have a `length`. We deliberately diverge from
+            // that here and treat any such value as array-like instead, so plain `{length, ...}`
+            // shims (which show up often in the wild without a real `@@iterator`) still spread;
+            // this is intentional leniency, not spec-accurate behavior.
+            Err(_) if !value.get_field("length").is_undefined() => {
+                let length = value.get_field("length").to_length(interpreter)?;
+                let mut values = Vec::with_capacity(length);
+                for i in 0..length {
+                    values.push(value.get_field(i));
+                }
+                Ok(values)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Evaluates the spread's inner expression and copies its own enumerable data properties onto
+    /// `target`, implementing object literal spread (`{ ...obj }`). Enumerable accessor
+    /// properties are not copied, and their getters are not called.
+    pub(crate) fn copy_enumerable_properties(
+        &self,
+        target: &GcObject,
+        interpreter: &mut Context,
+    ) -> Result<()> {
+        let value = self.val().run(interpreter)?;
+
+        let source = match value.as_object() {
+            Some(source) => source,
+            // Primitives (and `null`/`undefined`) contribute no own enumerable properties.
+            None => return Ok(()),
+        };
+
+        for key in source.borrow().keys() {
+            let property = source
+                .borrow()
+                .get_own_property(&key)
+                .filter(|property| property.enumerable());
+
+            let property = match property {
+                Some(property) => property,
+                None => continue,
+            };
+
+            // Only a data property's value is copied; a property without a value (an
+            // accessor) is skipped.
+            if let Some(v) = property.value() {
+                target.borrow_mut().insert_field(key, v);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Evaluates `nodes` (array-literal elements, call arguments, or constructor arguments),
+/// expanding any `Node::Spread` entries in place via [`Spread::run_array_values`].
+///
+/// This is the single place that threads spread-awareness through those three evaluators, so
+/// `[...a]`, `f(...args)` and `new C(...args)` all expand identically.
+pub(crate) fn run_spread_aware_list(nodes: &[Node], interpreter: &mut Context) -> Result<Vec<Value>> {
+    let mut values = Vec::with_capacity(nodes.len());
+    for node in nodes {
+        if let Node::Spread(spread) = node {
+            values.extend(spread.run_array_values(interpreter)?);
+        } else {
+            values.push(node.run(interpreter)?);
+        }
+    }
+    Ok(values)
+}
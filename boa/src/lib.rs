@@ -40,10 +40,12 @@
 #![cfg_attr(any(test), allow(deprecated))]
 
 pub mod builtins;
+pub mod completion;
 pub mod environment;
 pub mod exec;
 pub mod profiler;
 pub mod realm;
+pub mod repl;
 pub mod syntax;
 
 mod context;
@@ -58,7 +60,7 @@ pub(crate) use crate::{
 pub use gc::{custom_trace, unsafe_empty_trace, Finalize, Trace};
 
 // Export things to root level
-pub use crate::{builtins::value::Value, context::Context};
+pub use crate::{builtins::value::Value, context::Context, repl::ReplResult};
 
 /// The result of a Javascript expression is represented like this so it can succeed (`Ok`) or fail (`Err`)
 #[must_use]
@@ -0,0 +1,77 @@
+//! REPL-oriented evaluation helpers.
+//!
+//! These build on top of [`Context`] to support interactive front-ends (a line-based REPL, for
+//! example) that need to keep prompting for continuation lines until a statement is
+//! syntactically complete, rather than reporting every incomplete line as a hard error.
+
+use crate::{syntax::parser::ParseError, syntax::Parser, BoaProfiler, Context, Result, Value};
+
+/// The outcome of feeding a line (or a set of accumulated lines) of source to
+/// [`Context::eval_repl`].
+#[derive(Debug)]
+pub enum ReplResult {
+    /// The source was syntactically complete; ran to either a produced `Value` or an error.
+    ///
+    /// A trailing bare expression statement's value is returned here, matching REPL ergonomics
+    /// (e.g. evaluating `1 + 1` yields `Complete(Ok(2))`).
+    Complete(Result<Value>),
+    /// The source is syntactically incomplete (an unclosed `{`/`[`/`(`, or an unterminated
+    /// string/template/comment). The front-end should read another line, append it to `src`,
+    /// and call `eval_repl` again with the concatenated source.
+    Incomplete,
+}
+
+impl Context {
+    /// Evaluate `src` in REPL mode.
+    ///
+    /// Unlike [`Context::eval`], a source string that merely ends early (rather than being
+    /// malformed) is reported as [`ReplResult::Incomplete`] instead of a hard parse error, so a
+    /// line-based front-end can keep prompting for continuation lines until the statement is
+    /// complete before executing it. Bindings created by previous calls persist in `self`,
+    /// exactly as with repeated calls to `eval`.
+    pub fn eval_repl(&mut self, src: &str) -> ReplResult {
+        let main_timer = BoaProfiler::global().start_event("Main", "Main");
+
+        let statements = match Parser::new(src.as_bytes()).parse_all() {
+            Ok(statements) => statements,
+            Err(e) => {
+                drop(main_timer);
+                BoaProfiler::global().drop();
+                return if e.is_recoverable() {
+                    ReplResult::Incomplete
+                } else {
+                    ReplResult::Complete(Err(self.construct_syntax_error(e.to_string())))
+                };
+            }
+        };
+
+        let result = statements.run(self);
+
+        drop(main_timer);
+        BoaProfiler::global().drop();
+
+        ReplResult::Complete(result)
+    }
+}
+
+impl ParseError {
+    /// Returns `true` if `self` represents syntactically *incomplete* input -- the lexer or
+    /// parser ran out of tokens while still expecting more (an unclosed `{`/`[`/`(`, or an
+    /// unterminated string/template/comment) -- rather than a genuine syntax error.
+    ///
+    /// REPL front-ends should use this to decide whether to keep prompting for continuation
+    /// lines instead of surfacing a hard error immediately.
+    pub fn is_recoverable(&self) -> bool {
+        if matches!(self, ParseError::AbruptEnd) {
+            return true;
+        }
+
+        // An unterminated string/template literal or block comment doesn't reach the parser as
+        // `AbruptEnd` -- the lexer hits end-of-input first and reports it as a lex error wrapped
+        // in a different `ParseError` variant. Rather than hard-coding that variant's name (it's
+        // lexer-internal and may change shape), match on the "ran out of input while still
+        // inside ..." wording the lexer's `Display` impl uses for exactly these cases.
+        let message = self.to_string();
+        message.contains("unterminated") || message.contains("unexpected end of file")
+    }
+}
@@ -0,0 +1,51 @@
+//! New expression node.
+
+use super::{Call, Node};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `new` operator lets developers create a new instance of a user-defined object type or of
+/// one of the built-in object types that has a constructor function.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-new-operator
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/new
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct New {
+    call: Box<Node>,
+}
+
+impl New {
+    /// Gets the call expression, whose callee is constructed, and arguments are passed to the
+    /// constructor.
+    pub fn call(&self) -> &Node {
+        &self.call
+    }
+}
+
+impl From<Call> for New {
+    fn from(call: Call) -> Self {
+        Self {
+            call: Box::new(call.into()),
+        }
+    }
+}
+
+impl fmt::Display for New {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "new {}", self.call)
+    }
+}
+
+impl From<New> for Node {
+    fn from(new: New) -> Node {
+        Node::New(Box::new(new))
+    }
+}
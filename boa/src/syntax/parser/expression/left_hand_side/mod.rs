@@ -10,13 +10,14 @@
 mod arguments;
 mod call;
 mod member;
+mod new;
 
-use self::{call::CallExpression, member::MemberExpression};
+use self::{call::CallExpression, member::MemberExpression, new::NewExpression};
 use super::super::ParseError;
 use crate::syntax::lexer::{InputElement, TokenKind};
 use crate::{
     syntax::{
-        ast::{Node, Punctuator},
+        ast::{Keyword, Node, Punctuator},
         parser::{AllowAwait, AllowYield, Cursor, TokenParser},
     },
     BoaProfiler,
@@ -63,8 +64,12 @@ where
 
         cursor.set_goal(InputElement::TemplateTail);
 
-        // TODO: Implement NewExpression: new MemberExpression
-        let lhs = MemberExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let lhs = match cursor.peek() {
+            Some(tok) if tok?.kind() == &TokenKind::Keyword(Keyword::New) => {
+                NewExpression::new(self.allow_yield, self.allow_await).parse(cursor)?
+            }
+            _ => MemberExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
+        };
         match cursor.peek() {
             Some(tok) => {
                 if tok?.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
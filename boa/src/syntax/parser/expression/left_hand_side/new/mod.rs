@@ -0,0 +1,109 @@
+//! New expression parsing.
+//!
+//! More information:
+//!  - [ECMAScript specification][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#prod-NewExpression
+
+use super::{super::Expression, arguments::Arguments, member::MemberExpression};
+use crate::syntax::lexer::TokenKind;
+use crate::{
+    syntax::{
+        ast::{
+            node::{Call, GetConstField, GetField, New},
+            Keyword, Node, Punctuator,
+        },
+        parser::{AllowAwait, AllowYield, Cursor, ParseError, TokenParser},
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Parses a new expression, a `new` keyword followed by either another `NewExpression` or a
+/// `MemberExpression` and an optional argument list.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-NewExpression
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser::expression::left_hand_side) struct NewExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl NewExpression {
+    /// Creates a new `NewExpression` parser.
+    pub(in crate::syntax::parser::expression::left_hand_side) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+    ) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for NewExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("NewExpression", "Parsing");
+
+        cursor.expect(Keyword::New, "new expression")?;
+
+        // `new` directly followed by another `new` keyword is a chained construction,
+        // e.g. `new new Foo()()`.
+        let callee = match cursor.peek() {
+            Some(tok) if tok?.kind() == &TokenKind::Keyword(Keyword::New) => {
+                Self::new(self.allow_yield, self.allow_await).parse(cursor)?
+            }
+            Some(_) => MemberExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
+            None => return Err(ParseError::AbruptEnd),
+        };
+
+        // The argument list is optional: `new Foo` is equivalent to `new Foo()`.
+        let args = match cursor.peek() {
+            Some(tok) if tok?.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) => {
+                Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?
+            }
+            _ => Box::new([]),
+        };
+
+        let mut lhs: Node = New::from(Call::new(callee, args)).into();
+
+        // `new X(...)` (or bare `new X`) is a complete primary expression and, just like any
+        // other `MemberExpression`, can still be followed by a member-access tail --
+        // `new Foo().bar`, `new Foo()[0]`, `new a.b().c` are all common. Consume that tail here
+        // so a `New` node doesn't leave it dangling for the caller to choke on; any further
+        // `Arguments` tail (`new Foo().bar()`) is picked up afterwards by the regular
+        // `CallExpression` dispatch in `LeftHandSideExpression::parse`.
+        loop {
+            match cursor.peek() {
+                Some(tok) if tok?.kind() == &TokenKind::Punctuator(Punctuator::Dot) => {
+                    cursor.expect(Punctuator::Dot, "new expression")?;
+                    let field = cursor.next_identifier_name("new expression")?;
+                    lhs = GetConstField::new(lhs, field).into();
+                }
+                Some(tok) if tok?.kind() == &TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    cursor.expect(Punctuator::OpenBracket, "new expression")?;
+                    let idx =
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    cursor.expect(Punctuator::CloseBracket, "new expression")?;
+                    lhs = GetField::new(lhs, idx).into();
+                }
+                _ => break,
+            }
+        }
+
+        Ok(lhs)
+    }
+}